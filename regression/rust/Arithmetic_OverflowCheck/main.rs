@@ -0,0 +1,24 @@
+// FUTURE: signed-overflow and negation checks on +, *, -, and unary minus
+// are not yet instrumented anywhere in this checkout (no front-end source
+// tree); this fixture documents the intended diagnostics and is expected to
+// fail until that engine work lands.
+fn main() {
+  let a : i32 = i32::MAX;
+  let b = a + 1;
+
+  assert!(b > a);
+
+  let p : i32 = i32::MAX;
+  let q = p * 2;
+
+  assert!(q > p);
+
+  let n : i32 = i32::MIN;
+  let m = 0 - n;
+
+  assert!(m >= 0);
+
+  let neg = -n;
+
+  assert!(neg >= 0);
+}
\ No newline at end of file
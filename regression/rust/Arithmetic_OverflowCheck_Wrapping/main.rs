@@ -0,0 +1,15 @@
+// FUTURE: the wrap-mode variant of overflow handling is not yet instrumented
+// anywhere in this checkout (no front-end source tree); this fixture
+// documents the intended wraparound semantics and is expected to fail until
+// that engine work lands.
+fn main() {
+  let a : i32 = i32::MAX;
+  let b = a + 1;
+
+  assert!(b == i32::MIN);
+
+  let n : i32 = i32::MIN;
+  let m = 0 - n;
+
+  assert!(m == i32::MIN);
+}
\ No newline at end of file
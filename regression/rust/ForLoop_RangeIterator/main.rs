@@ -0,0 +1,32 @@
+// FUTURE: desugaring `for` over ranges/slices/.enumerate() into bounded
+// symbolic loops with post-loop state preserved is not yet implemented in
+// the Rust front end; this fixture documents the intended behavior and is
+// expected to fail until that engine work lands.
+fn main() {
+  let mut total : u32 = 0;
+
+  for n in 1..100 {
+    total += n;
+  }
+
+  assert!(total == 4950);
+
+  let arr = [10, 20, 30, 40];
+  let mut sum = 0;
+
+  for x in &arr {
+    assert!(*x >= 10);
+    sum += x;
+  }
+
+  assert!(sum == 100);
+
+  let mut last_index = 0;
+
+  for (i, v) in arr.iter().enumerate() {
+    assert!(*v == arr[i]);
+    last_index = i;
+  }
+
+  assert!(last_index == 3);
+}
\ No newline at end of file
@@ -0,0 +1,28 @@
+// FUTURE: if-let discriminant/payload lowering for Option/Result is not yet
+// implemented in the Rust goto-conversion pass; this fixture documents the
+// intended behavior and is expected to fail until that front-end work lands.
+fn main() {
+  let text = "3.1415";
+
+  if let Ok(x) = text.parse::<f64>() {
+    assert!(x > 0.0);
+  } else {
+    assert!(false);
+  }
+
+  let maybe : Option<u32> = Some(7);
+
+  if let Some(x) = maybe {
+    assert!(x == 7);
+  } else {
+    assert!(false);
+  }
+
+  let empty : Option<u32> = None;
+
+  if let Some(x) = empty {
+    assert!(false);
+  } else {
+    assert!(empty.is_none());
+  }
+}
\ No newline at end of file
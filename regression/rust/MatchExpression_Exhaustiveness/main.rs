@@ -0,0 +1,56 @@
+// FUTURE: exhaustiveness checking and per-arm match lowering is not yet
+// implemented in the Rust goto-conversion pass; this fixture documents the
+// intended behavior (including the fallthrough check in test.desc) and is
+// expected to fail until that front-end work lands.
+enum Light {
+  Red,
+  Yellow,
+  Green,
+}
+
+fn main() {
+  let a : u32;
+
+  let b =
+  match a % 3 {
+    0 => {
+      assert!(a != 5);
+      0
+    }
+    1 => {
+      assert!(a > 0);
+      1
+    }
+    _ => {
+      assert!(a > 1);
+      2
+    }
+  };
+
+  assert!(b < 3);
+
+  let c : u32;
+
+  let d =
+  match c {
+    0..=9 => c + 101,
+    10..=100 => c * 11,
+    _ => c,
+  };
+
+  assert!(d > 100);
+
+  let light = Light::Green;
+
+  let next = match light {
+    Light::Red => Light::Green,
+    Light::Yellow => Light::Red,
+    Light::Green => Light::Yellow,
+  };
+
+  match next {
+    Light::Red => assert!(false),
+    Light::Yellow => assert!(true),
+    Light::Green => assert!(false),
+  }
+}
\ No newline at end of file
@@ -0,0 +1,29 @@
+// FUTURE: per-function configurable recursion unwind bounds, and the
+// havoc-vs-assert toggle for exceeding them, are not yet implemented in the
+// engine; --unwindset in test.desc is a spec for the intended CLI surface, not a
+// working option in this checkout.
+fn even(x: usize) -> bool {
+  if x < 2 {
+    false
+  } else if x == 2 {
+    true
+  } else {
+    even(x - 2)
+  }
+}
+
+fn f(n: usize) -> usize {
+  if n == 0 {
+    1
+  } else {
+    1 + f(n - 1)
+  }
+}
+
+fn main() {
+  assert!(even(2));
+  assert!(!even(1));
+  assert!(even(10));
+
+  assert!(f(5000) == 5001);
+}
\ No newline at end of file
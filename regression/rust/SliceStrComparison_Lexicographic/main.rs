@@ -0,0 +1,23 @@
+// FUTURE: lexicographic ordering for slice/&str comparisons is not yet
+// modeled in the Rust goto-conversion pass; this fixture documents the
+// intended behavior and is expected to fail until that front-end work lands.
+fn main() {
+  let a : [isize; 5] = [2, 2, 2, 2, 2];
+  let b : [isize; 6] = [2, 2, 2, 2, 2, 2];
+
+  assert!(a < b);
+  assert!(b >= a);
+  assert!(a != b);
+
+  let c : [isize; 5] = [2, 2, 2, 2, 3];
+
+  assert!(a < c);
+  assert!(c >= a);
+
+  let s1 = "abc";
+  let s2 = "abd";
+
+  assert!(s1 < s2);
+  assert!(s2 >= s1);
+  assert!(s1 <= s1);
+}
\ No newline at end of file
@@ -0,0 +1,22 @@
+// FUTURE: field-sensitive symbolic-aggregate handling for struct literals and
+// `..base` functional update is not yet implemented in the Rust front end;
+// this fixture documents the intended behavior and is expected to fail until
+// that engine work lands.
+struct Point {
+  x: i32,
+  y: i32,
+}
+
+fn main() {
+  let origin = Point { x: 0, y: 0 };
+
+  let right = Point { x: origin.x + 10, ..origin };
+
+  assert_eq!(right.x, 10);
+  assert_eq!(right.y, 0);
+
+  let up = Point { y: right.y + 5, ..right };
+
+  assert_eq!(up.x, 10);
+  assert_eq!(up.y, 5);
+}
\ No newline at end of file